@@ -3,6 +3,8 @@
 extern crate rand;
 
 mod sim;
+#[cfg(feature = "wasm")]
+mod wasm;
 
 fn main()
 {
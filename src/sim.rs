@@ -1,6 +1,10 @@
 use rand::{self, Rng};
+use std::collections::HashSet;
 
-/// A single cell in the simulation
+/// A single cell in the simulation. `#[repr(u8)]` keeps the layout stable
+/// so `LifeSimulator::cells_ptr` can be read as a byte buffer from JS in a
+/// WASM build.
+#[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Cell
 {
@@ -37,10 +41,287 @@ impl ::std::fmt::Display for Cell
 /// ..* A cell will die if there are more than 0.40625n alive neighbors.
 /// ..* A cell will come to life if the number of alive neighbors a is
 ///     0.34375n <= a <= 0.40625n.
+/// * `Custom`
+/// ..* A dead cell comes to life if its alive neighbor count is in `birth`.
+/// ..* A live cell stays alive if its alive neighbor count is in `survive`.
+/// ..* This is the familiar "Bx/Sy" life-like rule notation, and can
+///     express non-contiguous neighbor counts that `BasicRules` and
+///     `PercentageRules` cannot (e.g. Conway's own B3/S23).
 pub enum LifeRules
 {
   BasicRules,
   PercentageRules,
+  Custom { birth: Vec<u32>, survive: Vec<u32> },
+}
+
+impl LifeRules
+{
+  /// Parses a rule specification in the familiar `Bx/Sy` notation (as used
+  /// by Golly and other life-like cellular automaton tools), e.g.
+  /// `"B3/S23"` for Conway's classic rule. Returns `LifeRules::Custom`
+  /// with the parsed birth and survive neighbor-count sets.
+  pub fn parse(spec: &str) -> Result<LifeRules, String>
+  {
+    let mut birth = Option::None;
+    let mut survive = Option::None;
+
+    for segment in spec.split('/')
+    {
+      let mut chars = segment.chars();
+      match chars.next()
+      {
+        Option::Some('B') | Option::Some('b') =>
+        {
+          birth = Option::Some(LifeRules::parse_counts(chars.as_str())?);
+        }
+        Option::Some('S') | Option::Some('s') =>
+        {
+          survive = Option::Some(LifeRules::parse_counts(chars.as_str())?);
+        }
+        _ => return Result::Err(format!("unrecognized rule segment: {}", segment)),
+      }
+    }
+
+    match (birth, survive)
+    {
+      (Option::Some(birth), Option::Some(survive)) =>
+      {
+        Result::Ok(LifeRules::Custom { birth: birth, survive: survive })
+      }
+      _ => Result::Err(format!("rule spec must contain both a B and an S segment: {}", spec)),
+    }
+  }
+
+  fn parse_counts(digits: &str) -> Result<Vec<u32>, String>
+  {
+    digits.chars()
+      .map(|c| c.to_digit(10).ok_or_else(|| format!("invalid neighbor count in rule spec: {}", c)))
+      .collect()
+  }
+}
+
+/// Determines how `get_neighbor_indices` treats coordinates that fall
+/// outside of the grid in a given dimension.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BoundaryCondition
+{
+  /// Out-of-bounds neighbors simply don't exist, so cells near the edges
+  /// of the grid have fewer neighbors than interior cells.
+  Bounded,
+  /// The grid wraps around on itself in every dimension, so the cell
+  /// past the last one is the first one again (and vice versa). This
+  /// turns the N-dimensional grid into a torus.
+  Wrapping,
+  /// Coordinates outside of the grid reflect back onto the nearest edge
+  /// cell.
+  Mirror,
+}
+
+/// A 2D pattern of live cells, parsed from a plain-text or RLE layout,
+/// that can be stamped into a simulation at an arbitrary origin via
+/// `LifeSimulator::stamp_pattern`.
+#[derive(Clone, Debug)]
+pub struct Pattern
+{
+  width: usize,
+  height: usize,
+  live_cells: Vec<(usize, usize)>, // (col, row) offsets within the pattern
+}
+
+impl Pattern
+{
+  /// Parses a 2D ASCII layout into a `Pattern`. `*` and `X` mark a live
+  /// cell; `-` and `.` mark a dead cell. Rows are separated by newlines.
+  pub fn parse_pattern(text: &str) -> Result<Pattern, String>
+  {
+    let mut live_cells = Vec::new();
+    let mut width = 0;
+    let mut height = 0;
+
+    for (row, line) in text.lines().enumerate()
+    {
+      let line = line.trim_right();
+      if line.is_empty()
+      {
+        continue;
+      }
+
+      height = row + 1;
+      if line.len() > width
+      {
+        width = line.len();
+      }
+
+      for (col, ch) in line.chars().enumerate()
+      {
+        match ch
+        {
+          '*' | 'X' => live_cells.push((col, row)),
+          '-' | '.' => {}
+          _ => return Result::Err(format!("unrecognized pattern character: {}", ch)),
+        }
+      }
+    }
+
+    Result::Ok(Pattern { width: width, height: height, live_cells: live_cells })
+  }
+
+  /// Parses a Golly-style run-length-encoded (RLE) pattern: an optional
+  /// `#`-prefixed comment header, an `x = W, y = H, ...` size line, and a
+  /// body made of run-count/tag pairs (`b` dead, `o` alive, `$` end of
+  /// row, `!` end of pattern).
+  pub fn load_rle(text: &str) -> Result<Pattern, String>
+  {
+    let mut width = 0;
+    let mut height = 0;
+    let mut body = String::new();
+
+    for line in text.lines()
+    {
+      let line = line.trim();
+      if line.is_empty() || line.starts_with('#')
+      {
+        continue;
+      }
+      else if line.starts_with('x') || line.starts_with('X')
+      {
+        for field in line.split(',')
+        {
+          let mut parts = field.splitn(2, '=');
+          let key = parts.next().unwrap_or("").trim();
+          let val = parts.next().unwrap_or("").trim();
+
+          match key
+          {
+            "x" =>
+            {
+              width = val.parse()
+                .map_err(|_| format!("invalid width in RLE header: {}", val))?;
+            }
+            "y" =>
+            {
+              height = val.parse()
+                .map_err(|_| format!("invalid height in RLE header: {}", val))?;
+            }
+            _ => {}
+          }
+        }
+      }
+      else
+      {
+        body.push_str(line);
+      }
+    }
+
+    let mut live_cells = Vec::new();
+    let mut row = 0;
+    let mut col = 0;
+    let mut run = String::new();
+
+    'rle: for ch in body.chars()
+    {
+      if ch.is_digit(10)
+      {
+        run.push(ch);
+        continue;
+      }
+
+      let count = if run.is_empty()
+      {
+        1
+      }
+      else
+      {
+        run.parse().map_err(|_| format!("invalid run count in RLE body: {}", run))?
+      };
+      run.clear();
+
+      match ch
+      {
+        'b' => col += count,
+        'o' =>
+        {
+          for i in 0..count
+          {
+            live_cells.push((col + i, row));
+          }
+          col += count;
+        }
+        '$' =>
+        {
+          row += count;
+          col = 0;
+        }
+        '!' => break 'rle,
+        _ => return Result::Err(format!("unrecognized RLE token: {}", ch)),
+      }
+    }
+
+    Result::Ok(Pattern { width: width, height: height, live_cells: live_cells })
+  }
+}
+
+/// A strategy used to seed the initial state of a simulation, via
+/// `LifeSimulator::seed_with_noise`.
+pub enum SeedStrategy
+{
+  /// Each cell is independently alive with probability `density`. This is
+  /// what `randomize_grid` does, and produces structureless static.
+  Uniform { density: f64 },
+  /// Each cell is alive where a coherent noise field, sampled at the
+  /// cell's N-dimensional coordinate scaled by `frequency`, exceeds
+  /// `threshold`. Unlike `Uniform`, nearby cells are correlated, so this
+  /// yields clustered initial colonies.
+  Noise { seed: u32, frequency: f64, threshold: f64 },
+}
+
+/// A minimal N-dimensional value-noise field: the coordinate is scaled by
+/// `frequency`, its surrounding integer lattice corners are hashed to
+/// pseudo-random values via `lattice_hash`, and those are interpolated.
+/// This keeps the core crate dependency-light instead of pulling in an
+/// external Perlin/OpenSimplex noise crate.
+fn coherent_noise(coords: &[u32], frequency: f64, seed: u32) -> f64
+{
+  let scaled: Vec<f64> = coords.iter().map(|&c| c as f64 * frequency).collect();
+  let dim = scaled.len();
+  let corners = 1usize << dim;
+
+  let mut total = 0.0;
+  for corner in 0..corners
+  {
+    let mut lattice = Vec::with_capacity(dim);
+    let mut weight = 1.0;
+
+    for d in 0..dim
+    {
+      let base = scaled[d].floor();
+      let frac = scaled[d] - base;
+      let far_corner = (corner >> d) & 1 == 1;
+
+      lattice.push(base as i64 + if far_corner { 1 } else { 0 });
+      weight *= if far_corner { frac } else { 1.0 - frac };
+    }
+
+    total += weight * lattice_hash(&lattice, seed);
+  }
+
+  total
+}
+
+/// Hashes an integer lattice point (salted with `seed`) to a pseudo-random
+/// value in `[0, 1)`, used as the noise value at that lattice point.
+fn lattice_hash(lattice: &[i64], seed: u32) -> f64
+{
+  let mut hash = seed as u64 ^ 0x9e3779b97f4a7c15;
+
+  for &coord in lattice
+  {
+    hash ^= coord as u64;
+    hash = hash.wrapping_mul(0xff51afd7ed558ccd);
+    hash ^= hash >> 33;
+  }
+
+  (hash % 1_000_000) as f64 / 1_000_000.0
 }
 
 /// A simulation of Conway's Game of Life, generalized to N-dimensions.
@@ -48,11 +329,13 @@ pub enum LifeRules
 pub struct LifeSimulator
 {
   cells: Vec<Cell>,
+  counts: Vec<u32>, // cached count of alive neighbors for each cell
+  active: HashSet<usize>, // cells whose neighbor count may have changed
   dim: u32,
   size: usize, // number of cells in each dimension
-  min_neighbors: u32, // min number of neighbors to live
-  min_breed_neighbors: u32, // min number of neighbors needed to come to life
-  max_neighbors: u32, // max number of neighbors before death
+  birth: HashSet<u32>, // neighbor counts that bring a dead cell to life
+  survive: HashSet<u32>, // neighbor counts that keep a live cell alive
+  boundary: BoundaryCondition, // how neighbors are found at the grid edges
 }
 
 #[allow(dead_code)]
@@ -65,43 +348,146 @@ impl LifeSimulator
   pub fn new(rules: LifeRules, dimensions: u32, size: usize) -> Self
   {
     let num_neighbors = 3u32.pow(dimensions) - 1;
-    let min_neighbors = match rules
-    {
-      LifeRules::BasicRules => num_neighbors / 4,
-      LifeRules::PercentageRules => num_neighbors / 4,
-    };
-    let min_breed_neighbors = match rules
+
+    let (birth, survive) = match rules
     {
-      LifeRules::BasicRules => (num_neighbors + 1) / 3,
+      LifeRules::BasicRules =>
+      {
+        let min_neighbors = num_neighbors / 4;
+        let breed_neighbors = (num_neighbors + 1) / 3;
+        let max_neighbors = (num_neighbors + 1) / 3;
+
+        ((breed_neighbors...max_neighbors).collect(), (min_neighbors...max_neighbors).collect())
+      }
       LifeRules::PercentageRules =>
       {
-        ((num_neighbors as f64) * 0.34375).ceil() as u32
+        let min_neighbors = num_neighbors / 4;
+        let breed_neighbors = ((num_neighbors as f64) * 0.34375).ceil() as u32;
+        let max_neighbors = ((num_neighbors as f64) * 0.40625) as u32;
+
+        ((breed_neighbors...max_neighbors).collect(), (min_neighbors...max_neighbors).collect())
+      }
+      LifeRules::Custom { birth, survive } =>
+      {
+        (birth.into_iter().collect(), survive.into_iter().collect())
       }
     };
-    let max_neighbors = match rules
-    {
-      LifeRules::BasicRules => (num_neighbors + 1) / 3,
-      LifeRules::PercentageRules => ((num_neighbors as f64) * 0.40625) as u32,
-    };
+
+    // `step` only re-examines cells that flipped last generation plus
+    // their neighbors - it never visits a dead cell with no live
+    // neighbors at all. A rule that births at a neighbor count of 0 (e.g.
+    // `LifeRules::parse("B0/S23")`) would need every such cell to come
+    // alive every generation, which the active-set engine can't express.
+    assert!(!birth.contains(&0),
+      "a birth count of 0 (e.g. a B0 rule) isn't supported: the active-set \
+       engine never re-examines a dead cell with no live neighbors");
+
+    let num_cells = size.pow(dimensions);
 
     LifeSimulator {
-      cells: vec![Cell::Dead; size.pow(dimensions)],
+      cells: vec![Cell::Dead; num_cells],
+      counts: vec![0; num_cells],
+      active: HashSet::new(),
       dim: dimensions,
       size: size,
-      min_neighbors: min_neighbors,
-      min_breed_neighbors: min_breed_neighbors,
-      max_neighbors: max_neighbors,
+      birth: birth,
+      survive: survive,
+      boundary: BoundaryCondition::Bounded,
     }
   }
 
+  /// Sets the boundary condition used by `get_neighbor_indices`. Defaults
+  /// to `BoundaryCondition::Bounded`.
+  pub fn set_boundary(&mut self, boundary: BoundaryCondition)
+  {
+    self.boundary = boundary;
+  }
+
   /// Randomizes the state of all cells in the simulation.
   pub fn randomize_grid(&mut self)
   {
     let mut rng = rand::thread_rng();
-    for cell in self.cells.iter_mut()
+    let mut next_active = HashSet::new();
+
+    for index in 0..self.cells.len()
     {
-      *cell = Cell::from(rng.gen::<bool>());
+      let new_state = Cell::from(rng.gen::<bool>());
+      self.flip_cell(index, new_state, &mut next_active);
     }
+
+    self.active = next_active;
+  }
+
+  /// Seeds the grid according to `strategy`, replacing whatever live
+  /// cells were there before. `SeedStrategy::Uniform` behaves like
+  /// `randomize_grid`; `SeedStrategy::Noise` samples a coherent noise
+  /// field instead, producing spatially-correlated starting colonies.
+  pub fn seed_with_noise(&mut self, strategy: SeedStrategy)
+  {
+    let mut next_active = HashSet::new();
+
+    match strategy
+    {
+      SeedStrategy::Uniform { density } =>
+      {
+        let mut rng = rand::thread_rng();
+        for index in 0..self.cells.len()
+        {
+          let alive = rng.gen::<f64>() < density;
+          self.flip_cell(index, Cell::from(alive), &mut next_active);
+        }
+      }
+      SeedStrategy::Noise { seed, frequency, threshold } =>
+      {
+        for index in 0..self.cells.len()
+        {
+          let coords = self.index_to_coords(index);
+          let value = coherent_noise(&coords, frequency, seed);
+          self.flip_cell(index, Cell::from(value > threshold), &mut next_active);
+        }
+      }
+    }
+
+    // `next_active` only holds cells touched by a flip above. On a fresh
+    // all-dead grid that's everything, but reseeding a grid that already
+    // had live cells can leave some alive and neighborless-of-a-flip, so
+    // they'd never be re-examined by `step` again. Rebuild the active set
+    // from the final board instead: every live cell plus its neighbors.
+    self.active = HashSet::new();
+    for index in 0..self.cells.len()
+    {
+      if self.cells[index] == Cell::Alive
+      {
+        self.active.insert(index);
+        for n_ind in self.get_neighbor_indices(index)
+        {
+          self.active.insert(n_ind);
+        }
+      }
+    }
+  }
+
+  /// Sets the cell at `index` to `new_state`, keeping the cached neighbor
+  /// counts (and the active set used by `step`) consistent. Does nothing
+  /// if the cell is already in `new_state`. Any direct mutation through
+  /// `mut_cell` bypasses this bookkeeping.
+  fn flip_cell(&mut self, index: usize, new_state: Cell, active: &mut HashSet<usize>)
+  {
+    if self.cells[index] == new_state
+    {
+      return;
+    }
+
+    self.cells[index] = new_state;
+    let delta: i64 = if new_state == Cell::Alive { 1 } else { -1 };
+
+    for n_ind in self.get_neighbor_indices(index)
+    {
+      self.counts[n_ind] = (self.counts[n_ind] as i64 + delta) as u32;
+      active.insert(n_ind);
+    }
+
+    active.insert(index);
   }
 
   fn tagged_coords_to_index(size: usize, dim: u32, coords: &[u32]) -> usize
@@ -158,12 +544,71 @@ impl LifeSimulator
     self.cells[index]
   }
 
-  /// Returns a mutable reference to the cell at the given `index`.
+  /// Returns a mutable reference to the cell at the given `index`. Note
+  /// that mutating through this reference does not update the cached
+  /// neighbor counts or active set used by `step`.
   pub fn mut_cell(&mut self, index: usize) -> &mut Cell
   {
     &mut self.cells[index]
   }
 
+  /// Returns a raw pointer to the contiguous cell buffer, for zero-copy
+  /// reads from JS in a WASM build. The pointer is valid until the next
+  /// call that mutates `self`.
+  pub fn cells_ptr(&self) -> *const Cell
+  {
+    self.cells.as_ptr()
+  }
+
+  /// Returns the number of cells in the buffer pointed to by `cells_ptr`.
+  pub fn len(&self) -> usize
+  {
+    self.cells.len()
+  }
+
+  /// Projects a 2D cross-section of the grid: `fixed_coords` is a full
+  /// coordinate (one entry per dimension) whose entries for the two varying
+  /// `axes` (as `(x_axis, y_axis)` dimension indices) are ignored; those two
+  /// are swept over the full grid size. Cells
+  /// are returned in row-major order (all of row 0, then row 1, ...).
+  pub fn slice_2d(&self, fixed_coords: &[u32], axes: (usize, usize)) -> Vec<Cell>
+  {
+    let (x_axis, y_axis) = axes;
+    let mut coords = fixed_coords.to_vec();
+    let mut out = Vec::with_capacity(self.size * self.size);
+
+    for y in 0..self.size
+    {
+      for x in 0..self.size
+      {
+        coords[x_axis] = x as u32;
+        coords[y_axis] = y as u32;
+        out.push(self.get_cell(self.coords_to_index(&coords)));
+      }
+    }
+
+    out
+  }
+
+  /// Renders a 2D cross-section of the grid (see `slice_2d`) using the
+  /// `Cell` `Display` impl, so callers don't have to loop over
+  /// `coords_to_index` by hand the way `main` does.
+  pub fn render(&self, fixed_coords: &[u32], axes: (usize, usize)) -> String
+  {
+    let mut out = String::new();
+
+    for (i, cell) in self.slice_2d(fixed_coords, axes).iter().enumerate()
+    {
+      out.push_str(&cell.to_string());
+      if (i + 1) % self.size == 0
+      {
+        out.push('\n');
+      }
+    }
+
+    out
+  }
+
   /// Get the indices for the neighbors of the cell at the given `index`.
   pub fn get_neighbor_indices(&self, index: usize) -> Vec<usize>
   {
@@ -196,69 +641,463 @@ impl LifeSimulator
         {
           1 =>
           {
-            match n_coords[d].checked_sub(1)
+            if self.boundary == BoundaryCondition::Wrapping
+            {
+              ((n_coords[d] as usize + self.size - 1) % self.size) as u32
+            }
+            else
             {
-              Option::Some(val) => val,
-              Option::None => break 'neighbor, // No neighbor will exist < 0
+              match n_coords[d].checked_sub(1)
+              {
+                Option::Some(val) => val,
+                Option::None => match self.boundary
+                {
+                  // No neighbor would exist < 0; Mirror reflects back onto
+                  // the nearest edge cell, Bounded has no neighbor there.
+                  BoundaryCondition::Mirror => 0,
+                  _ => continue 'neighbor,
+                },
+              }
             }
           }
           2 =>
           {
-            match n_coords[d].checked_add(1)
+            if self.boundary == BoundaryCondition::Wrapping
             {
-              Option::Some(val) => val,
-              Option::None => break 'neighbor,
+              ((n_coords[d] as usize + 1) % self.size) as u32
+            }
+            else
+            {
+              match n_coords[d].checked_add(1)
+              {
+                Option::Some(val) => val,
+                Option::None => continue 'neighbor,
+              }
             }
           }
           _ => n_coords[d],
         };
-        // The neighbor only truly exists if it is within bounds.
-        // The underflow check before checks for minimum bounds, and below
-        // checks for maximum bounds.
+        // The neighbor only truly exists if it is within bounds. The
+        // underflow case above already handled Wrapping and Mirror, so
+        // reaching `self.size` here only happens for the max-bound case.
         if n_coords[d] as usize == self.size
         {
-          break 'neighbor;
+          match self.boundary
+          {
+            BoundaryCondition::Mirror => n_coords[d] = self.size as u32 - 1,
+            _ => continue 'neighbor,
+          }
         }
       }
 
-      // Convert the coordinates to an index and add it to the list
-      n_inds.push(self.coords_to_index(&n_coords));
+      // Under Mirror, a reflected coordinate can land back on the cell's
+      // own position (e.g. a corner cell reflecting in both axes), or on
+      // the same neighbor reached via a different offset (e.g. reflecting
+      // -1 and clamping +1 can both land on 0). Under Wrapping, the same
+      // collision happens when `self.size <= 2`, since `-1` and `+1` both
+      // wrap to the same coordinate. Skip the former and de-duplicate the
+      // latter below so a cell never counts itself, and a real neighbor is
+      // never counted twice.
+      if n_coords != coords
+      {
+        n_inds.push(self.coords_to_index(&n_coords));
+      }
+    }
+
+    if self.boundary == BoundaryCondition::Mirror || self.boundary == BoundaryCondition::Wrapping
+    {
+      let mut seen = HashSet::new();
+      n_inds.retain(|&ind| seen.insert(ind));
     }
 
     n_inds
   }
 
+  /// Stamps `pattern` into the grid such that its (0, 0) cell lands at
+  /// `origin`, an N-dimensional coordinate with one entry per dimension of
+  /// this simulation. The pattern's two axes are placed along the first
+  /// two dimensions. Returns an error (and leaves the grid unchanged) if
+  /// the pattern would extend beyond `self.size` in either axis.
+  pub fn stamp_pattern(&mut self, pattern: &Pattern, origin: &[u32]) -> Result<(), String>
+  {
+    assert_eq!(origin.len(), self.dim as usize);
+    assert!(self.dim >= 2, "stamp_pattern needs at least 2 dimensions for its 2D pattern");
+
+    if origin[0] as usize + pattern.width > self.size ||
+      origin[1] as usize + pattern.height > self.size
+    {
+      return Result::Err(format!(
+        "pattern of size {}x{} does not fit at origin {:?} in a grid of size {}",
+        pattern.width, pattern.height, origin, self.size));
+    }
+
+    // The first two axes were already checked against the pattern's
+    // extent above; every other axis just needs its single origin
+    // coordinate to be in bounds.
+    if origin[2..].iter().any(|&coord| coord as usize >= self.size)
+    {
+      return Result::Err(format!(
+        "origin {:?} is out of bounds for a grid of size {}", origin, self.size));
+    }
+
+    let mut coords = origin.to_vec();
+    let mut next_active = HashSet::new();
+
+    for &(col, row) in &pattern.live_cells
+    {
+      coords[0] = origin[0] + col as u32;
+      coords[1] = origin[1] + row as u32;
+      let index = self.coords_to_index(&coords);
+      self.flip_cell(index, Cell::Alive, &mut next_active);
+    }
+
+    self.active.extend(next_active);
+    Result::Ok(())
+  }
+
   /// Performs a single step in the simulation.
+  ///
+  /// Rather than recomputing every cell's neighbor count from scratch
+  /// (which is O(size^dim * 3^dim) and becomes untenable in 4D/5D), only
+  /// the cells in the active set - those that flipped last generation,
+  /// plus their neighbors - are re-examined against their cached neighbor
+  /// count. This turns a step into O(active cells * 3^dim).
   pub fn step(&mut self)
   {
-    let last_state = self.clone();
-    let mut alive_neighbors;
+    let mut to_flip = Vec::new();
+
+    for &index in &self.active
+    {
+      let alive_neighbors = self.counts[index];
+      let is_alive = self.cells[index] == Cell::Alive;
+
+      // A dead cell is born if its neighbor count is in `birth`; a live
+      // cell survives if its neighbor count is in `survive`. Otherwise it
+      // dies or stays dead.
+      let next_alive = if is_alive
+      {
+        self.survive.contains(&alive_neighbors)
+      }
+      else
+      {
+        self.birth.contains(&alive_neighbors)
+      };
+
+      if next_alive != is_alive
+      {
+        to_flip.push((index, Cell::from(next_alive)));
+      }
+    }
+
+    let mut next_active = HashSet::new();
+    for (index, new_state) in to_flip
+    {
+      self.flip_cell(index, new_state, &mut next_active);
+    }
+
+    self.active = next_active;
+  }
+}
+
+/// A configuration discovered by `search_oscillator`: which cells within
+/// the searched bounding box are alive.
+#[derive(Clone, Debug)]
+pub struct FoundPattern
+{
+  width: usize,
+  height: usize,
+  live_cells: Vec<(usize, usize)>,
+}
+
+impl FoundPattern
+{
+  /// Converts this discovery into a `Pattern` that can be stamped back
+  /// into a simulator (via `LifeSimulator::stamp_pattern`) for
+  /// verification.
+  pub fn to_pattern(&self) -> Pattern
+  {
+    Pattern { width: self.width, height: self.height, live_cells: self.live_cells.clone() }
+  }
+}
+
+/// Searches a `width` x `height` bounding box, embedded as a 2D slice of a
+/// `dim`-dimensional, `size`-per-axis grid, for a configuration that
+/// returns to itself after `period` generations under `rules`: a still
+/// life when `period == 1`, an oscillator of that period otherwise. The
+/// all-dead board is never returned, since it's a fixed point of any rule
+/// and would otherwise be reported as a spurious solution for any period.
+///
+/// This is a backtracking constraint search: every cell in the box is an
+/// unknown (alive or dead). Cells outside the box start dead, so a box
+/// cell's neighbor count after one generation is fully determined as soon
+/// as every one of its neighbors *inside* the box has been guessed; when
+/// `period == 1` that lets `search_cells` forward-propagate each guess and
+/// backtrack the instant a cell's required next state contradicts it,
+/// rather than waiting for a full guess to be simulated. For `period > 1`
+/// there is no single-step target to propagate against, so the search
+/// falls back to guessing the whole box and simulating it forward.
+///
+/// Each candidate is verified (in `evolves_to_self`) not just on the box
+/// but on a surrounding margin `period` cells wide. Since activity can
+/// only spread by one cell per generation, that margin is wide enough
+/// that nothing stamped outside it can reach back inside during `period`
+/// steps - so requiring the whole margin to return to dead catches
+/// patterns that spill live cells past the box's edges instead of
+/// reporting them as clean oscillators. Only the 2D plane holding the box
+/// is compared; when `dim > 2`, cells off that plane are not checked.
+pub fn search_oscillator(
+  rules: LifeRules, dim: u32, size: usize,
+  width: usize, height: usize, period: u32) -> Option<FoundPattern>
+{
+  assert!(dim >= 2, "search_oscillator needs at least 2 dimensions for its bounding box");
+  assert!(period >= 1, "search_oscillator needs a period of at least 1");
+
+  let margin = period as usize;
+  assert!(width + 2 * margin <= size && height + 2 * margin <= size,
+    "search box of {}x{} with a margin of {} generations does not fit in a grid of size {}",
+    width, height, margin, size);
+
+  let sim = LifeSimulator::new(rules, dim, size);
+  let mut guess = vec![Cell::Dead; width * height];
+
+  if search_cells(&sim, width, height, period, margin, &mut guess, 0)
+  {
+    let live_cells = guess.iter().enumerate()
+      .filter(|&(_, &cell)| cell == Cell::Alive)
+      .map(|(i, _)| (i % width, i / width))
+      .collect();
+
+    Option::Some(FoundPattern { width: width, height: height, live_cells: live_cells })
+  }
+  else
+  {
+    Option::None
+  }
+}
+
+fn search_cells(
+  sim: &LifeSimulator, width: usize, height: usize, period: u32, margin: usize,
+  guess: &mut Vec<Cell>, next_index: usize) -> bool
+{
+  if next_index == guess.len()
+  {
+    // The all-dead board is a fixed point of any life-like rule (nothing
+    // can come alive from nothing), so it would trivially "return to
+    // itself" after any number of generations. Reject it so the search
+    // keeps looking for a real configuration.
+    if guess.iter().all(|&cell| cell == Cell::Dead)
+    {
+      return false;
+    }
+
+    return evolves_to_self(sim, width, height, period, margin, guess);
+  }
 
-    for (index, cell) in self.cells.iter_mut().enumerate()
+  for &state in &[Cell::Dead, Cell::Alive]
+  {
+    guess[next_index] = state;
+
+    // Forward-propagate the one-generation constraint implied by this
+    // guess: once every in-box neighbor of a cell is known, its state one
+    // generation later is fully determined, and can be checked without
+    // waiting for the whole board to be guessed. Only sound for
+    // `period == 1`, where the state one generation later has to match
+    // the initial guess exactly.
+    if period != 1 || one_step_consistent(sim, guess, width, height, next_index)
     {
-      // Get number of alive neighbors
-      alive_neighbors = 0;
-      let n_inds = last_state.get_neighbor_indices(index);
-      for n_ind in n_inds
+      if search_cells(sim, width, height, period, margin, guess, next_index + 1)
+      {
+        return true;
+      }
+    }
+  }
+
+  false
+}
+
+/// Returns the alive/dead state one generation later of the cell at
+/// `(row, col)` (which may be a box cell, or just outside the box in the
+/// 2D plane), assuming every cell outside the box starts dead. Since
+/// cells outside the box never change on their own, this only depends on
+/// `(row, col)`'s neighbors that fall inside the box.
+fn next_state(sim: &LifeSimulator, guess: &[Cell], width: usize, height: usize, row: i64, col: i64) -> bool
+{
+  let in_box = |r: i64, c: i64| r >= 0 && c >= 0 && (r as usize) < height && (c as usize) < width;
+
+  let mut alive_neighbors = 0;
+  for dy in -1i64...1
+  {
+    for dx in -1i64...1
+    {
+      if dx == 0 && dy == 0
+      {
+        continue;
+      }
+      if in_box(row + dy, col + dx) && guess[(row + dy) as usize * width + (col + dx) as usize] == Cell::Alive
+      {
+        alive_neighbors += 1;
+      }
+    }
+  }
+
+  let was_alive = in_box(row, col) && guess[row as usize * width + col as usize] == Cell::Alive;
+
+  if was_alive { sim.survive.contains(&alive_neighbors) } else { sim.birth.contains(&alive_neighbors) }
+}
+
+/// Checks the one-generation-later constraint for every cell whose
+/// next state was just fully determined by guessing
+/// `guess[just_assigned]` - i.e. every cell in the 3x3 neighborhood of
+/// `just_assigned` for which `just_assigned` is the last index it depends
+/// on: its last in-box neighbor, or for a box cell the later of that and
+/// its own index. A box cell must match its own guess; a cell just
+/// outside the box (in the margin) must stay dead.
+fn one_step_consistent(
+  sim: &LifeSimulator, guess: &[Cell], width: usize, height: usize, just_assigned: usize) -> bool
+{
+  let row0 = (just_assigned / width) as i64;
+  let col0 = (just_assigned % width) as i64;
+
+  for dy in -1i64...1
+  {
+    for dx in -1i64...1
+    {
+      let (row, col) = (row0 + dy, col0 + dx);
+      let in_box = row >= 0 && col >= 0 && (row as usize) < height && (col as usize) < width;
+
+      // A box cell's own guess is part of what's being checked (it has to
+      // match `becomes_alive`), not just its neighbors, so the trigger
+      // index is the later of "last neighbor assigned" and "this cell
+      // itself assigned". Margin cells outside the box have no guess of
+      // their own, so only their neighbors matter.
+      let trigger = if in_box
+      {
+        let self_index = row as usize * width + col as usize;
+        match last_in_box_neighbor(width, height, row, col)
+        {
+          Option::Some(neighbor_idx) => neighbor_idx.max(self_index),
+          Option::None => self_index,
+        }
+      }
+      else
+      {
+        match last_in_box_neighbor(width, height, row, col)
+        {
+          Option::Some(neighbor_idx) => neighbor_idx,
+          Option::None => continue,
+        }
+      };
+
+      if trigger != just_assigned
+      {
+        continue;
+      }
+
+      let becomes_alive = next_state(sim, guess, width, height, row, col);
+
+      if in_box
       {
-        if last_state.get_cell(n_ind) == Cell::Alive
+        let currently_alive = guess[row as usize * width + col as usize] == Cell::Alive;
+        if becomes_alive != currently_alive
         {
-          alive_neighbors += 1;
+          return false;
         }
       }
+      else if becomes_alive
+      {
+        return false;
+      }
+    }
+  }
+
+  true
+}
 
-      // Check whether the cell should be set to dead, set to alive, or kept
-      // in its current state
-      if alive_neighbors < last_state.min_neighbors ||
-        alive_neighbors > last_state.max_neighbors
+/// The highest raster index among `(row, col)`'s in-box neighbors, or
+/// `Option::None` if it has none. `(row, col)`'s alive-neighbor count is
+/// fully known exactly once every index up to this one has been guessed.
+fn last_in_box_neighbor(width: usize, height: usize, row: i64, col: i64) -> Option<usize>
+{
+  let mut last = Option::None;
+
+  for dy in -1i64...1
+  {
+    for dx in -1i64...1
+    {
+      if dx == 0 && dy == 0
       {
-        *cell = Cell::Dead;
+        continue;
       }
-      else if alive_neighbors >= last_state.min_breed_neighbors &&
-               alive_neighbors <= last_state.max_neighbors
+
+      let (r, c) = (row + dy, col + dx);
+      if r >= 0 && c >= 0 && (r as usize) < height && (c as usize) < width
       {
-        *cell = Cell::Alive;
+        let idx = (r as usize) * width + (c as usize);
+        last = Option::Some(match last { Option::Some(cur) => cur.max(idx), Option::None => idx });
       }
     }
   }
+
+  last
+}
+
+/// Embeds `guess` into a scratch simulation (cloned from `sim` so the
+/// caller's rules, dimension count, and boundary condition carry over) at
+/// an offset of `margin` cells in from the grid's edge, steps it forward
+/// `period` generations using the same `get_neighbor_indices` + rule
+/// predicate as `LifeSimulator::step`, and checks whether both the box
+/// and its `margin`-cell-wide surrounding ring returned to their initial
+/// state (the box to `guess`, the ring to dead).
+fn evolves_to_self(
+  sim: &LifeSimulator, width: usize, height: usize, period: u32, margin: usize,
+  guess: &[Cell]) -> bool
+{
+  let mut scratch = sim.clone();
+  let mut coords = vec![0u32; scratch.dim as usize];
+  let mut next_active = HashSet::new();
+
+  for (i, &cell) in guess.iter().enumerate()
+  {
+    coords[0] = (margin + i % width) as u32;
+    coords[1] = (margin + i / width) as u32;
+    let index = scratch.coords_to_index(&coords);
+    scratch.flip_cell(index, cell, &mut next_active);
+  }
+
+  scratch.active = next_active;
+
+  for _ in 0..period
+  {
+    scratch.step();
+  }
+
+  let ext_width = width + 2 * margin;
+  let ext_height = height + 2 * margin;
+
+  for row in 0..ext_height
+  {
+    for col in 0..ext_width
+    {
+      coords[0] = col as u32;
+      coords[1] = row as u32;
+      let index = scratch.coords_to_index(&coords);
+
+      let in_box = row >= margin && row < margin + height && col >= margin && col < margin + width;
+      let expected = if in_box
+      {
+        guess[(row - margin) * width + (col - margin)]
+      }
+      else
+      {
+        Cell::Dead
+      };
+
+      if scratch.get_cell(index) != expected
+      {
+        return false;
+      }
+    }
+  }
+
+  true
 }
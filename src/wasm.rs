@@ -0,0 +1,57 @@
+//! JS-friendly bindings for running the simulator in a browser via WASM.
+//! Gated behind the `wasm` feature so the core crate stays dependency-light
+//! for non-browser consumers.
+
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+use sim::{Cell, LifeRules, LifeSimulator};
+
+/// A thin wrapper around `LifeSimulator` exposing a JS-friendly API.
+#[wasm_bindgen]
+pub struct WasmLifeSimulator
+{
+  inner: LifeSimulator,
+}
+
+#[wasm_bindgen]
+impl WasmLifeSimulator
+{
+  #[wasm_bindgen(constructor)]
+  pub fn new(dimensions: u32, size: usize) -> WasmLifeSimulator
+  {
+    WasmLifeSimulator { inner: LifeSimulator::new(LifeRules::BasicRules, dimensions, size) }
+  }
+
+  /// Advances the simulation by one generation.
+  pub fn step(&mut self)
+  {
+    self.inner.step();
+  }
+
+  /// Randomizes the state of all cells in the simulation.
+  pub fn randomize_grid(&mut self)
+  {
+    self.inner.randomize_grid();
+  }
+
+  /// Returns a pointer to the contiguous `Cell` buffer, for a zero-copy
+  /// view (e.g. a `Uint8Array` over WASM memory) from JS.
+  pub fn cells_ptr(&self) -> *const Cell
+  {
+    self.inner.cells_ptr()
+  }
+
+  /// Returns the number of cells in the buffer pointed to by `cells_ptr`.
+  pub fn len(&self) -> usize
+  {
+    self.inner.len()
+  }
+
+  /// Renders a 2D cross-section of the grid as a displayable string.
+  pub fn render(&self, fixed_coords: Vec<u32>, x_axis: usize, y_axis: usize) -> String
+  {
+    self.inner.render(&fixed_coords, (x_axis, y_axis))
+  }
+}